@@ -0,0 +1,171 @@
+use std::fmt::{Debug};
+
+use deepsize::{DeepSizeOf};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+use sha2::{
+
+    Digest,
+    Sha256,
+};
+
+/// The Signed Tree Head returned by a log's `/ct/v1/get-sth` endpoint.
+#[derive(Clone, Debug, DeepSizeOf)]
+#[derive(Serialize, Deserialize)]
+pub struct SignedTreeHead {
+    pub(crate) tree_size: usize,
+    pub(crate) timestamp: i64,
+    pub(crate) root_hash: Vec<u8>,
+    pub(crate) signature: Vec<u8>,
+}
+
+impl<'a> SignedTreeHead {
+    pub fn tree_size(&'a self) -> usize {
+        self.tree_size
+    }
+
+    pub fn timestamp(&'a self) -> i64 {
+        self.timestamp
+    }
+
+    pub fn root_hash(&'a self) -> &'a [u8] {
+        self.root_hash.as_slice()
+    }
+
+    pub fn signature(&'a self) -> &'a [u8] {
+        self.signature.as_slice()
+    }
+}
+
+/// The tree size and root hash an [`Entry`](crate::Entry) was verified against.
+#[derive(Clone, Debug, DeepSizeOf)]
+#[derive(Serialize, Deserialize)]
+pub struct AuditProof {
+    pub(crate) tree_size: usize,
+    pub(crate) root_hash: Vec<u8>,
+}
+
+impl<'a> AuditProof {
+    pub(crate) fn new(tree_size: usize, root_hash: &[u8]) -> AuditProof {
+        AuditProof {
+            tree_size,
+            root_hash: root_hash.to_vec(),
+        }
+    }
+
+    pub fn tree_size(&'a self) -> usize {
+        self.tree_size
+    }
+
+    pub fn root_hash(&'a self) -> &'a [u8] {
+        self.root_hash.as_slice()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct InclusionProof {
+    pub(crate) leaf_index: usize,
+    pub(crate) audit_path: Vec<Vec<u8>>,
+}
+
+/// The RFC 6962 Merkle leaf hash: `SHA256(0x00 || leaf_input)`.
+pub(crate) fn leaf_hash(leaf_input: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+
+    hasher.update([0x00]);
+    hasher.update(leaf_input);
+
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+
+    hasher.finalize().into()
+}
+
+/// Recomputes the Merkle root from a leaf and its audit path, per RFC 6962 section 2.1.1.
+pub(crate) fn verify_inclusion(leaf: [u8; 32], leaf_index: usize, tree_size: usize, audit_path: &[Vec<u8>], root_hash: &[u8]) -> bool {
+    if tree_size == 0 { return false }
+
+    let mut index = leaf_index;
+    let mut last = tree_size - 1;
+    let mut node = leaf;
+
+    for sibling in audit_path {
+        node = if index % 2 == 1 || index == last {
+            self::node_hash(sibling.as_slice(), &node)
+        } else {
+            self::node_hash(&node, sibling.as_slice())
+        };
+
+        index /= 2;
+        last /= 2;
+    }
+
+    node.as_slice() == root_hash
+}
+
+/// Verifies that `second_hash` at `second_size` is a append-only continuation of `first_hash`
+/// at `first_size`, per the RFC 6962 section 2.1.2 consistency proof algorithm.
+pub(crate) fn verify_consistency(first_size: usize, second_size: usize, audit_path: &[Vec<u8>], first_hash: &[u8], second_hash: &[u8]) -> bool {
+    if first_size == 0 { return true }
+    if first_size > second_size { return false }
+
+    if first_size == second_size {
+        return audit_path.is_empty() && first_hash == second_hash
+    }
+
+    let mut node = first_size - 1;
+    let mut last = second_size - 1;
+
+    while node % 2 == 1 {
+        node /= 2;
+        last /= 2;
+    }
+
+    let mut path = audit_path.iter();
+
+    let (mut old_hash, mut new_hash) = if node > 0 {
+        match path.next() {
+            Some(hash) => (hash.clone(), hash.clone()),
+            None => return false,
+        }
+    } else {
+        (first_hash.to_vec(), first_hash.to_vec())
+    };
+
+    while node > 0 {
+        if node % 2 == 1 {
+            let sibling = match path.next() { Some(hash) => hash, None => return false };
+
+            old_hash = self::node_hash(sibling, &old_hash).to_vec();
+            new_hash = self::node_hash(sibling, &new_hash).to_vec();
+        } else if node < last {
+            let sibling = match path.next() { Some(hash) => hash, None => return false };
+
+            new_hash = self::node_hash(&new_hash, sibling).to_vec();
+        }
+
+        node /= 2;
+        last /= 2;
+    }
+
+    while last > 0 {
+        let sibling = match path.next() { Some(hash) => hash, None => return false };
+
+        new_hash = self::node_hash(&new_hash, sibling).to_vec();
+        last /= 2;
+    }
+
+    old_hash == first_hash && new_hash == second_hash
+}
@@ -1,5 +1,5 @@
 use std::{
-    
+
     fmt::{
 
         Formatter as FmtFormatter,
@@ -7,29 +7,50 @@ use std::{
         Debug as FmtDebug,
     },
 
+    io::{
+
+        Cursor,
+        Read,
+    },
+
     net::{IpAddr},
 };
 
+use byteorder::{
+
+    ReadBytesExt,
+    BigEndian,
+};
+
 use chrono::{
-    
+
     NaiveDateTime,
-    DateTime, 
+    DateTime,
     Utc,
 };
 
 use deepsize::{DeepSizeOf};
 
+use sha2::{
+
+    Digest,
+    Sha256,
+};
+
 use serde::{
 
-    Deserialize, 
+    Deserialize,
     Serialize,
 };
 
 use x509_parser::prelude::{
 
-    X509Certificate, 
-    TbsCertificate, 
-    GeneralName, 
+    ExtendedKeyUsage,
+    X509Certificate,
+    TbsCertificate,
+    ParsedExtension,
+    KeyUsage,
+    GeneralName,
     FromDer,
 };
 
@@ -111,15 +132,44 @@ impl<'a> CertificateAlternateName {
     }
 }
 
+/// An embedded SCT found in a certificate's `1.3.6.1.4.1.11129.2.4.2` extension.
+#[derive(Clone, Debug, DeepSizeOf)]
+#[derive(Serialize, Deserialize)]
+pub struct EmbeddedSct {
+    pub(crate) log_id: Vec<u8>,
+    pub(crate) timestamp: i64,
+}
+
+impl<'a> EmbeddedSct {
+    pub fn log_id(&'a self) -> &'a [u8] {
+        self.log_id.as_slice()
+    }
+
+    pub fn timestamp(&'a self) -> i64 {
+        self.timestamp
+    }
+}
+
 #[derive(Clone, DeepSizeOf)]
 #[derive(Serialize, Deserialize)]
 pub struct Certificate {
     pub(crate) issuer: Option<String>,
+    pub(crate) issuer_dn: String,
     pub(crate) authority: bool,
     pub(crate) organization: Option<String>,
     pub(crate) subject_name: Option<String>,
+    pub(crate) subject_dn: String,
     pub(crate) subject_alternate: Vec<CertificateAlternateName>,
     pub(crate) validity: CertificateValidity,
+    pub(crate) serial: String,
+    pub(crate) fingerprint: Vec<u8>,
+    pub(crate) key_algorithm: String,
+    pub(crate) subject_key_id: Option<Vec<u8>>,
+    pub(crate) authority_key_id: Option<Vec<u8>>,
+    pub(crate) key_usage: Vec<String>,
+    pub(crate) extended_key_usage: Vec<String>,
+    pub(crate) precertificate: bool,
+    pub(crate) embedded_scts: Vec<EmbeddedSct>,
     pub(crate) encoded: Vec<u8>,
 }
 
@@ -132,6 +182,10 @@ impl<'a> Certificate {
         None
     }
 
+    pub fn issuer_dn(&'a self) -> &'a str {
+        self.issuer_dn.as_str()
+    }
+
     pub fn authority(&'a self) -> bool {
         self.authority
     }
@@ -152,6 +206,10 @@ impl<'a> Certificate {
         None
     }
 
+    pub fn subject_dn(&'a self) -> &'a str {
+        self.subject_dn.as_str()
+    }
+
     pub fn subject_alternate(&'a self) -> &'a [CertificateAlternateName] {
         self.subject_alternate.as_slice()
     }
@@ -160,6 +218,45 @@ impl<'a> Certificate {
         self.validity
     }
 
+    pub fn serial(&'a self) -> &'a str {
+        self.serial.as_str()
+    }
+
+    pub fn fingerprint(&'a self) -> &'a [u8] {
+        self.fingerprint.as_slice()
+    }
+
+    pub fn key_algorithm(&'a self) -> &'a str {
+        self.key_algorithm.as_str()
+    }
+
+    pub fn subject_key_id(&'a self) -> Option<&'a [u8]> {
+        self.subject_key_id.as_deref()
+    }
+
+    pub fn authority_key_id(&'a self) -> Option<&'a [u8]> {
+        self.authority_key_id.as_deref()
+    }
+
+    pub fn key_usage(&'a self) -> &'a [String] {
+        self.key_usage.as_slice()
+    }
+
+    pub fn extended_key_usage(&'a self) -> &'a [String] {
+        self.extended_key_usage.as_slice()
+    }
+
+    /// Whether this leaf carries the CT poison extension (`1.3.6.1.4.1.11129.2.4.3`)
+    /// marking it as a precertificate rather than a final, issuable certificate.
+    pub fn is_precertificate(&'a self) -> bool {
+        self.precertificate
+    }
+
+    /// SCTs embedded in the certificate's `1.3.6.1.4.1.11129.2.4.2` extension.
+    pub fn embedded_scts(&'a self) -> &'a [EmbeddedSct] {
+        self.embedded_scts.as_slice()
+    }
+
     pub fn deep_size(&'a self) -> usize {
         self.deep_size_of()
     }
@@ -173,11 +270,21 @@ impl FmtDebug for Certificate {
     fn fmt(&self, formatter: &mut FmtFormatter<'_>) -> FmtResult {
         formatter.debug_struct("Certificate")
             .field("issuer", &(self.issuer()))
+            .field("issuer_dn", &(self.issuer_dn()))
             .field("authority", &(self.authority()))
             .field("organization", &(self.organization()))
             .field("subject_name", &(self.subject_name()))
+            .field("subject_dn", &(self.subject_dn()))
             .field("subject_alternate", &(self.subject_alternate()))
             .field("validity", &(self.validity()))
+            .field("serial", &(self.serial()))
+            .field("key_algorithm", &(self.key_algorithm()))
+            .field("subject_key_id", &(self.subject_key_id()))
+            .field("authority_key_id", &(self.authority_key_id()))
+            .field("key_usage", &(self.key_usage()))
+            .field("extended_key_usage", &(self.extended_key_usage()))
+            .field("precertificate", &(self.is_precertificate()))
+            .field("embedded_scts", &(self.embedded_scts()))
             .finish()
     }
 }
@@ -205,6 +312,9 @@ pub(crate) fn parse_certificate<'a>(data: &'a [u8]) -> Option<Certificate> {
         extension.value.general_names.as_slice()
     } else { Default::default() };
 
+    let issuer_dn = certificate.issuer().to_string();
+    let subject_dn = certificate.subject().to_string();
+
     let issuer = certificate.issuer().iter_organization()
         .filter_map(|name| name.as_str().ok())
         .next().and_then(|name| Some({
@@ -280,16 +390,162 @@ pub(crate) fn parse_certificate<'a>(data: &'a [u8]) -> Option<Certificate> {
         CertificateValidity::from_timestamps(begin, end)
     };
 
+    let serial = self::to_hex(certificate.raw_serial());
+    let key_algorithm = certificate.subject_pki.algorithm.algorithm.to_id_string();
+
+    let mut subject_key_id = None;
+    let mut authority_key_id = None;
+    let mut key_usage = Vec::new();
+    let mut extended_key_usage = Vec::new();
+    let mut precertificate = false;
+    let mut embedded_scts = Vec::new();
+
+    for extension in certificate.extensions() {
+        match extension.oid.to_id_string().as_str() {
+            "1.3.6.1.4.1.11129.2.4.3" => precertificate = true,
+            "1.3.6.1.4.1.11129.2.4.2" => embedded_scts = self::parse_embedded_scts(extension.value),
+            _ => {},
+        }
+
+        match extension.parsed_extension() {
+            ParsedExtension::SubjectKeyIdentifier(ref key_id) => {
+                subject_key_id = Some(key_id.0.to_vec());
+            },
+            ParsedExtension::AuthorityKeyIdentifier(ref identifier) => {
+                authority_key_id = identifier.key_identifier.as_ref()
+                    .map(|key_id| key_id.0.to_vec());
+            },
+            ParsedExtension::KeyUsage(ref usage) => {
+                key_usage = self::key_usage_flags(usage);
+            },
+            ParsedExtension::ExtendedKeyUsage(ref usage) => {
+                extended_key_usage = self::extended_key_usage_flags(usage);
+            },
+            _ => {},
+        }
+    }
+
     let encoded = data[..(data.len() - remaining.len())].to_vec();
 
+    let fingerprint = {
+        let mut hasher = Sha256::new();
+        hasher.update(encoded.as_slice());
+
+        hasher.finalize().to_vec()
+    };
+
     Some(Certificate {
 
-        issuer, 
+        issuer,
+        issuer_dn,
         authority,
         organization,
         subject_name,
+        subject_dn,
         subject_alternate,
         validity,
+        serial,
+        fingerprint,
+        key_algorithm,
+        subject_key_id,
+        authority_key_id,
+        key_usage,
+        extended_key_usage,
+        precertificate,
+        embedded_scts,
         encoded,
     })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn key_usage_flags(usage: &KeyUsage) -> Vec<String> {
+    let mut flags = Vec::new();
+
+    if usage.digital_signature() { flags.push(String::from("digital_signature")) }
+    if usage.non_repudiation() { flags.push(String::from("non_repudiation")) }
+    if usage.key_encipherment() { flags.push(String::from("key_encipherment")) }
+    if usage.data_encipherment() { flags.push(String::from("data_encipherment")) }
+    if usage.key_agreement() { flags.push(String::from("key_agreement")) }
+    if usage.key_cert_sign() { flags.push(String::from("key_cert_sign")) }
+    if usage.crl_sign() { flags.push(String::from("crl_sign")) }
+    if usage.encipher_only() { flags.push(String::from("encipher_only")) }
+    if usage.decipher_only() { flags.push(String::from("decipher_only")) }
+
+    flags
+}
+
+fn extended_key_usage_flags(usage: &ExtendedKeyUsage) -> Vec<String> {
+    let mut flags = Vec::new();
+
+    if usage.any { flags.push(String::from("any")) }
+    if usage.server_auth { flags.push(String::from("server_auth")) }
+    if usage.client_auth { flags.push(String::from("client_auth")) }
+    if usage.code_signing { flags.push(String::from("code_signing")) }
+    if usage.email_protection { flags.push(String::from("email_protection")) }
+    if usage.time_stamping { flags.push(String::from("time_stamping")) }
+    if usage.ocsp_signing { flags.push(String::from("ocsp_signing")) }
+
+    flags
+}
+
+/// Parses the TLS-encoded `SignedCertificateTimestampList` (RFC 6962 section 3.3) carried
+/// in the `1.3.6.1.4.1.11129.2.4.2` extension, whose value is itself a DER OCTET STRING.
+fn parse_embedded_scts(data: &[u8]) -> Vec<EmbeddedSct> {
+    let mut outer = Cursor::new(data);
+
+    let tag = match outer.read_u8() { Ok(tag) => tag, Err(_) => return Vec::new() };
+    if tag != 0x04 { return Vec::new() }
+
+    let first_length = match outer.read_u8() { Ok(byte) => byte, Err(_) => return Vec::new() };
+
+    let length = if first_length & 0x80 == 0 {
+        first_length as usize
+    } else {
+
+        let count = (first_length & 0x7f) as usize;
+        let mut length = 0usize;
+
+        for _ in 0..count {
+            let byte = match outer.read_u8() { Ok(byte) => byte, Err(_) => return Vec::new() };
+            length = (length << 8) | byte as usize;
+        }
+
+        length
+    };
+
+    let start = outer.position() as usize;
+    let end = start + length;
+
+    if end > data.len() { return Vec::new() }
+
+    let mut cursor = Cursor::new(&data[start..end]);
+
+    let list_length = match cursor.read_u16::<BigEndian>() { Ok(length) => length, Err(_) => return Vec::new() };
+    let list_end = cursor.position() as usize + list_length as usize;
+
+    let mut entries = Vec::new();
+
+    while (cursor.position() as usize) < list_end {
+        let sct_length = match cursor.read_u16::<BigEndian>() { Ok(length) => length, Err(_) => break };
+        let sct_end = cursor.position() as usize + sct_length as usize;
+
+        if cursor.read_u8().is_err() { break }
+
+        let mut log_id = [0u8; 32];
+        if cursor.read_exact(&mut log_id).is_err() { break }
+
+        let raw_timestamp = match cursor.read_u64::<BigEndian>() { Ok(timestamp) => timestamp, Err(_) => break };
+
+        entries.push(EmbeddedSct {
+            log_id: log_id.to_vec(),
+            timestamp: (raw_timestamp / 1000) as i64,
+        });
+
+        cursor.set_position(sct_end as u64);
+    }
+
+    entries
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::fmt::{Debug};
+use std::path::{PathBuf};
+use std::fs;
+use std::io::{Write};
+
+use serde::{
+
+    Deserialize,
+    Serialize,
+};
+
+/// A persistent spool for the last position a stream has fully consumed.
+pub trait Checkpoint: Debug + CheckpointClone {
+    fn load(&self) -> Option<usize>;
+    fn save(&mut self, position: usize);
+}
+
+/// Lets `Box<dyn Checkpoint>` be cloned, so [`StreamConfig`](crate::StreamConfig) stays
+/// `Clone` despite holding one. Implemented for free for any `Checkpoint` that is also `Clone`.
+pub trait CheckpointClone {
+    #[doc(hidden)]
+    fn clone_box(&self) -> Box<dyn Checkpoint>;
+}
+
+impl<T> CheckpointClone for T
+where T: Checkpoint + Clone + 'static {
+    fn clone_box(&self) -> Box<dyn Checkpoint> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Checkpoint> {
+    fn clone(&self) -> Box<dyn Checkpoint> {
+        self.clone_box()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckpointState {
+    position: usize
+}
+
+/// A [`Checkpoint`] that serializes the position as JSON to a file on disk.
+#[derive(Clone, Debug)]
+pub struct FileCheckpoint {
+    path: PathBuf,
+}
+
+impl FileCheckpoint {
+    pub fn new(path: impl Into<PathBuf>) -> FileCheckpoint {
+        FileCheckpoint {
+            path: path.into(),
+        }
+    }
+}
+
+impl Checkpoint for FileCheckpoint {
+    fn load(&self) -> Option<usize> {
+        let text = fs::read_to_string(&self.path).ok()?;
+        let CheckpointState { position } = serde_json::from_str(text.as_str()).ok()?;
+
+        Some(position)
+    }
+
+    fn save(&mut self, position: usize) {
+        let state = CheckpointState { position };
+
+        let text = match serde_json::to_string(&state) {
+            Ok(text) => text,
+            Err(_) => return,
+        };
+
+        // Written to a sibling temp file and renamed into place, so a crash or power loss
+        // mid-write can never leave `self.path` holding a truncated/corrupt checkpoint that
+        // `load()` would otherwise swallow and silently resume from the current log size.
+        let temp_path = self.path.with_extension("tmp");
+
+        let written = fs::File::create(&temp_path).and_then(|mut file| {
+            file.write_all(text.as_bytes())?;
+            file.sync_all()
+        });
+
+        if written.is_ok() {
+            let _ = fs::rename(&temp_path, &self.path);
+        }
+    }
+}
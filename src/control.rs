@@ -0,0 +1,84 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc};
+use std::time::{Duration};
+
+use tokio::sync::{Notify};
+
+/// A handle for hot-reconfiguring a stream started via [`stream_with_control`](crate::stream_with_control)
+/// while it runs: adjusting the idle `timeout` and per-request `batch` size, and pausing or
+/// resuming consumption, without tearing down and re-establishing the stream.
+#[derive(Clone)]
+pub struct StreamControl {
+    timeout: Arc<AtomicU64>,
+    batch: Arc<AtomicUsize>,
+    paused: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl StreamControl {
+    pub(crate) fn new(timeout: Duration, batch: usize) -> StreamControl {
+        StreamControl {
+            timeout: Arc::new(AtomicU64::new(timeout.as_millis() as u64)),
+            batch: Arc::new(AtomicUsize::new(batch.max(1))),
+            paused: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Hot-update the idle timeout used between polls.
+    pub fn set_timeout(&self, timeout: Duration) {
+        self.timeout.store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout.load(Ordering::Relaxed))
+    }
+
+    /// Hot-update the per-request batch size used by subsequent fetches.
+    pub fn set_batch(&self, batch: usize) {
+        self.batch.store(batch.max(1), Ordering::Relaxed);
+    }
+
+    pub fn batch(&self) -> usize {
+        self.batch.load(Ordering::Relaxed)
+    }
+
+    /// Parks the stream's drain loop after its current batch, without stopping entries
+    /// already in flight from being fetched.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub(crate) async fn wait_if_paused(&self) {
+        while self.is_paused() {
+            // Register the `Notified` future before rechecking the condition, per Tokio's
+            // documented pattern, so a `resume()` landing between the check and the await
+            // can't be missed as a dropped `notify_waiters()` with no one yet listening.
+            let notified = self.notify.notified();
+
+            if self.is_paused() {
+                notified.await;
+            }
+        }
+    }
+}
+
+impl Debug for StreamControl {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter.debug_struct("StreamControl")
+            .field("timeout", &self.timeout())
+            .field("batch", &self.batch())
+            .field("paused", &self.is_paused())
+            .finish()
+    }
+}
@@ -25,12 +25,21 @@ use serde::{
 };
 
 use crate::{
-    
-    certificate::{Certificate}, 
+
+    certificate::{Certificate},
     certificate,
 
+    audit::{
+
+        InclusionProof,
+        SignedTreeHead,
+        AuditProof,
+    },
+
+    audit,
+
     error::{
-        
+
         ResponseError,
         StreamError,
         LogError,
@@ -41,12 +50,26 @@ use crate::{
 use reqwest::{
 
     Client,
-    Url, 
+    Url,
 };
 
 #[derive(Debug, Deserialize)]
-struct Tree {
-    tree_size: usize
+struct SignedTreeHeadResponse {
+    tree_size: usize,
+    timestamp: i64,
+    sha256_root_hash: String,
+    tree_head_signature: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InclusionProofResponse {
+    leaf_index: usize,
+    audit_path: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConsistencyProofResponse {
+    consistency: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,12 +90,14 @@ pub enum Entry {
 
         timestamp: DateTime<Utc>,
         certificate: Certificate,
+        audit: Option<AuditProof>,
     },
 
     Pending {
 
         timestamp: DateTime<Utc>,
         certificate: Certificate,
+        audit: Option<AuditProof>,
     },
 }
 
@@ -92,6 +117,32 @@ impl<'a> Entry {
             Entry::Pending { ref certificate, .. } => certificate,
         }
     }
+
+    /// The tree size and root hash this entry was verified against, when [`verify`](crate::StreamConfig::verify) is enabled.
+    pub fn audit(&'a self) -> Option<&'a AuditProof> {
+        match self {
+
+            Entry::Signed { ref audit, .. } => audit.as_ref(),
+            Entry::Pending { ref audit, .. } => audit.as_ref(),
+        }
+    }
+
+    pub(crate) fn with_audit(self, proof: AuditProof) -> Entry {
+        match self {
+
+            Entry::Signed { timestamp, certificate, .. } => Entry::Signed {
+                audit: Some(proof),
+                timestamp,
+                certificate,
+            },
+
+            Entry::Pending { timestamp, certificate, .. } => Entry::Pending {
+                audit: Some(proof),
+                timestamp,
+                certificate,
+            },
+        }
+    }
 }
 
 fn parse_log_entry(data: &[u8]) -> Result<Entry, LogError> {
@@ -138,10 +189,12 @@ fn parse_log_entry(data: &[u8]) -> Result<Entry, LogError> {
                 Ok(match leaf_entry_variant {
                     0 => Entry::Signed {
                         timestamp: DateTime::from_utc(timestamp, Utc),
+                        audit: None,
                         certificate,
                     },
-                    1 => Entry::Pending { 
-                        timestamp: DateTime::from_utc(timestamp, Utc), 
+                    1 => Entry::Pending {
+                        timestamp: DateTime::from_utc(timestamp, Utc),
+                        audit: None,
                         certificate,
                     },
                     _ => return Err(LogError::UnsupportedEntry(leaf_entry_variant)),
@@ -153,14 +206,40 @@ fn parse_log_entry(data: &[u8]) -> Result<Entry, LogError> {
     }
 }
 
-fn read_log_size<T: AsRef<str>>(text: T) -> Result<usize, LogError> {
-    let Tree { tree_size } = serde_json::from_str(text.as_ref())
+fn read_signed_tree_head<T: AsRef<str>>(text: T) -> Result<SignedTreeHead, LogError> {
+    let SignedTreeHeadResponse { tree_size, timestamp, sha256_root_hash, tree_head_signature } = serde_json::from_str(text.as_ref())
         .map_err(|_| LogError::Parse("invalid log response!"))?;
 
-    Ok(tree_size)
+    let root_hash = base64::decode(sha256_root_hash)
+        .map_err(|_| LogError::Parse("invalid root hash encoding!"))?;
+
+    let signature = base64::decode(tree_head_signature)
+        .map_err(|_| LogError::Parse("invalid signature encoding!"))?;
+
+    Ok(SignedTreeHead { tree_size, timestamp, root_hash, signature })
 }
 
-fn read_log_entries<T: AsRef<str>>(text: T) -> Result<Vec<Entry>, LogError> {
+fn read_inclusion_proof<T: AsRef<str>>(text: T) -> Result<InclusionProof, LogError> {
+    let InclusionProofResponse { leaf_index, audit_path } = serde_json::from_str(text.as_ref())
+        .map_err(|_| LogError::Parse("invalid log response!"))?;
+
+    let audit_path = audit_path.into_iter()
+        .map(|node| base64::decode(node).map_err(|_| LogError::Parse("invalid audit path encoding!")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(InclusionProof { leaf_index, audit_path })
+}
+
+fn read_consistency_proof<T: AsRef<str>>(text: T) -> Result<Vec<Vec<u8>>, LogError> {
+    let ConsistencyProofResponse { consistency } = serde_json::from_str(text.as_ref())
+        .map_err(|_| LogError::Parse("invalid log response!"))?;
+
+    consistency.into_iter()
+        .map(|node| base64::decode(node).map_err(|_| LogError::Parse("invalid consistency path encoding!")))
+        .collect()
+}
+
+fn read_log_entries<T: AsRef<str>>(text: T) -> Result<Vec<(Entry, [u8; 32])>, LogError> {
     let TreeResponse { entries } = serde_json::from_str(text.as_ref())
         .map_err(|_| LogError::Parse("invalid log response!"))?;
 
@@ -172,7 +251,8 @@ fn read_log_entries<T: AsRef<str>>(text: T) -> Result<Vec<Entry>, LogError> {
         let data = base64::decode(leaf_input)
             .map_err(|_| LogError::Parse("invalid leaf encoding!"))?;
 
-        processed.push(self::parse_log_entry(data.as_slice())?);
+        let hash = audit::leaf_hash(data.as_slice());
+        processed.push((self::parse_log_entry(data.as_slice())?, hash));
     }
 
     Ok(processed)
@@ -203,7 +283,7 @@ fn get_rate_timeout(headers: &HeaderMap) -> Option<Duration> {
     None
 }
 
-pub(crate) async fn get_log_size<E>(client: Client, endpoint: E) -> Result<Response<usize>, StreamError> 
+pub(crate) async fn get_signed_tree_head<E>(client: Client, endpoint: E) -> Result<Response<SignedTreeHead>, StreamError>
 where E: AsRef<str> + Clone + Debug {
 
     let mut url = Url::parse(endpoint.as_ref())?;
@@ -222,8 +302,105 @@ where E: AsRef<str> + Clone + Debug {
 
                 let text = response.text()
                     .await?;
-                
-                return Ok(Response::Data(self::read_log_size(text)?))
+
+                return Ok(Response::Data(self::read_signed_tree_head(text)?))
+            },
+
+            429 => {
+
+                Ok(Response::Limited(self::get_rate_timeout({
+                    response.headers()
+                })))
+            },
+
+            code => {
+
+                match code {
+                    401..=499 => return Err(ResponseError::Client(code).into()),
+                    500..=599 => return Err(ResponseError::Server(code).into()),
+                    code => Ok(Response::Unhandled(code)),
+                }
+            },
+        }
+}
+
+pub(crate) async fn get_log_size<E>(client: Client, endpoint: E) -> Result<Response<usize>, StreamError>
+where E: AsRef<str> + Clone + Debug {
+
+    Ok(match self::get_signed_tree_head(client, endpoint).await? {
+
+        Response::Data(tree_head) => Response::Data(tree_head.tree_size()),
+        Response::Limited(duration) => Response::Limited(duration),
+        Response::Unhandled(code) => Response::Unhandled(code),
+    })
+}
+
+pub(crate) async fn get_inclusion_proof<U>(client: Client, url: U, hash: &[u8], tree_size: usize) -> Result<Response<InclusionProof>, StreamError>
+where U: AsRef<str> {
+
+    let mut url = Url::parse(url.as_ref())?;
+
+    url.path_segments_mut()
+        .map_err(|_| UrlError::RelativeUrlWithCannotBeABaseBase)?
+        .push("ct").push("v1").push("get-proof-by-hash");
+
+    let hash = base64::encode(hash);
+
+    let response = client.get(url.as_ref())
+        .query([("hash", hash), ("tree_size", tree_size.to_string())].as_ref())
+        .send().await?;
+
+    match response.status()
+        .as_u16() {
+
+            200 => {
+
+                let text = response.text()
+                    .await?;
+
+                return Ok(Response::Data(self::read_inclusion_proof(text)?))
+            },
+
+            429 => {
+
+                Ok(Response::Limited(self::get_rate_timeout({
+                    response.headers()
+                })))
+            },
+
+            code => {
+
+                match code {
+                    401..=499 => return Err(ResponseError::Client(code).into()),
+                    500..=599 => return Err(ResponseError::Server(code).into()),
+                    code => Ok(Response::Unhandled(code)),
+                }
+            },
+        }
+}
+
+pub(crate) async fn get_consistency_proof<U>(client: Client, url: U, first: usize, second: usize) -> Result<Response<Vec<Vec<u8>>>, StreamError>
+where U: AsRef<str> {
+
+    let mut url = Url::parse(url.as_ref())?;
+
+    url.path_segments_mut()
+        .map_err(|_| UrlError::RelativeUrlWithCannotBeABaseBase)?
+        .push("ct").push("v1").push("get-sth-consistency");
+
+    let response = client.get(url.as_ref())
+        .query([("first", first), ("second", second)].as_ref())
+        .send().await?;
+
+    match response.status()
+        .as_u16() {
+
+            200 => {
+
+                let text = response.text()
+                    .await?;
+
+                return Ok(Response::Data(self::read_consistency_proof(text)?))
             },
 
             429 => {
@@ -244,7 +421,7 @@ where E: AsRef<str> + Clone + Debug {
         }
 }
 
-pub(crate) async fn get_log_entries<U>(client: Client, url: U, position: usize, count: usize) -> Result<Response<Vec<Entry>>, StreamError> 
+pub(crate) async fn get_log_entries<U>(client: Client, url: U, position: usize, count: usize) -> Result<Response<Vec<(Entry, [u8; 32])>>, StreamError>
 where U: AsRef<str> {
 
     let mut url = Url::parse(url.as_ref())?;
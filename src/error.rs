@@ -16,6 +16,8 @@ pub enum LogError {
     UnsupportedEntry(u16),
     #[error("Parsing failed, info: {0}")]
     Parse(&'static str),
+    #[error("Proof verification failed!")]
+    ProofFailure,
 }
 
 #[derive(ThisError, Debug)]
@@ -24,23 +24,33 @@
 //! ```
 
 use std::{
-    
-    time::{Duration}, 
+
+    collections::{HashMap},
+    time::{Duration},
     fmt::{Debug},
 };
 
 use endpoint::Response;
 use tokio::runtime::{Runtime};
+use tokio::sync::{mpsc};
 
-use futures::{StreamExt};
+use futures::{Stream, StreamExt};
 use reqwest::{Client};
 
+use error::{LogError};
+
 pub mod error;
+pub mod checkpoint;
+pub mod audit;
+pub mod control;
 
 mod endpoint;
 
 pub use endpoint::{Entry};
 pub use error::{StreamError};
+pub use checkpoint::{Checkpoint, CheckpointClone};
+pub use audit::{AuditProof};
+pub use control::{StreamControl};
 
 #[derive(Debug, Clone)]
 pub struct StreamConfig<U>
@@ -49,129 +59,209 @@ where U: AsRef<str> + Clone + Debug {
     pub workers: Option<usize>,
     pub index: Option<usize>,
     pub batch: Option<usize>,
+    pub checkpoint: Option<Box<dyn Checkpoint>>,
+    pub verify: Option<bool>,
+    pub client: Option<Client>,
     pub url: U,
 }
 
-impl<U> StreamConfig<U> 
+impl<U> StreamConfig<U>
 where U: AsRef<str> + Clone + Debug {
     pub fn new(url: U) -> Self {
-        StreamConfig { 
+        StreamConfig {
 
             timeout: None,
             workers: None,
-            index: None, 
+            index: None,
             batch: None,
-            url, 
+            checkpoint: None,
+            verify: None,
+            client: None,
+            url,
         }
     }
 
     pub fn timeout(self, timeout: Duration) -> Self {
-        StreamConfig { 
-            timeout: Some(timeout), 
+        StreamConfig {
+            timeout: Some(timeout),
             workers: self.workers,
             index: self.index,
             batch: self.batch,
-            url: self.url, 
+            checkpoint: self.checkpoint,
+            verify: self.verify,
+            client: self.client,
+            url: self.url,
         }
     }
 
     pub fn workers(self, workers: usize) -> Self {
-        StreamConfig { 
+        StreamConfig {
             timeout: self.timeout,
             workers: Some(workers),
             index: self.index,
             batch: self.batch,
-            url: self.url, 
+            checkpoint: self.checkpoint,
+            verify: self.verify,
+            client: self.client,
+            url: self.url,
         }
     }
 
     pub fn index(self, index: usize) -> Self {
-        StreamConfig { 
-            timeout: self.timeout, 
+        StreamConfig {
+            timeout: self.timeout,
             workers: self.workers,
             index: Some(index),
             batch: self.batch,
-            url: self.url, 
+            checkpoint: self.checkpoint,
+            verify: self.verify,
+            client: self.client,
+            url: self.url,
         }
     }
 
     pub fn batch(self, batch: usize) -> Self {
-        StreamConfig { 
-            timeout: self.timeout, 
+        StreamConfig {
+            timeout: self.timeout,
             workers: self.workers,
             index: self.index,
             batch: Some(batch),
-            url: self.url, 
+            checkpoint: self.checkpoint,
+            verify: self.verify,
+            client: self.client,
+            url: self.url,
         }
     }
-}
 
-pub async fn stream<U, F>(config : StreamConfig<U>, mut handler: F) -> Result<(), StreamError>
-where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
+    /// Resume from a persisted position instead of the log's current size when `index` is unset.
+    /// The position is saved once per completed batch, and also as a final save when the
+    /// handler returns `false` to stop the stream.
+    pub fn checkpoint(self, checkpoint: impl Checkpoint + 'static) -> Self {
+        StreamConfig {
+            timeout: self.timeout,
+            workers: self.workers,
+            index: self.index,
+            batch: self.batch,
+            checkpoint: Some(Box::new(checkpoint)),
+            verify: self.verify,
+            client: self.client,
+            url: self.url,
+        }
+    }
 
-    let StreamConfig { 
-        timeout, 
-        workers,
-        index,
-        batch,
-        url, 
-    } = config;
+    /// When enabled, verify each entry's RFC 6962 Merkle inclusion proof against a fresh
+    /// Signed Tree Head before it reaches the handler, and attach the verified tree size
+    /// and root hash to the entry.
+    pub fn verify(self, verify: bool) -> Self {
+        StreamConfig {
+            timeout: self.timeout,
+            workers: self.workers,
+            index: self.index,
+            batch: self.batch,
+            checkpoint: self.checkpoint,
+            verify: Some(verify),
+            client: self.client,
+            url: self.url,
+        }
+    }
 
-    let client = Client::new();
-    let url = String::from({
-        url.as_ref()
-    });
+    /// Reuse an existing [`reqwest::Client`] (custom TLS roots, proxies, connection pool)
+    /// instead of creating a fresh default client.
+    pub fn client(self, client: Client) -> Self {
+        StreamConfig {
+            timeout: self.timeout,
+            workers: self.workers,
+            index: self.index,
+            batch: self.batch,
+            checkpoint: self.checkpoint,
+            verify: self.verify,
+            client: Some(client),
+            url: self.url,
+        }
+    }
+}
 
-    let workers = workers.unwrap_or(num_cpus::get()).max(1);
-    let batch = batch.unwrap_or(1000).max(1);
+/// A set of per-log [`StreamConfig`]s to be followed concurrently by [`stream_all`].
+#[derive(Debug)]
+pub struct MultiStreamConfig<U>
+where U: AsRef<str> + Clone + Debug {
+    pub configs: Vec<StreamConfig<U>>,
+}
 
-    let timeout = timeout.unwrap_or({
-        Duration::from_secs(1)
-    });
+impl<U> MultiStreamConfig<U>
+where U: AsRef<str> + Clone + Debug {
+    pub fn new() -> Self {
+        MultiStreamConfig {
+            configs: Vec::new(),
+        }
+    }
 
-    let size = loop {
-        
-        let response = endpoint::get_log_size(client.clone(), url.clone()).await?;
+    pub fn log(mut self, config: StreamConfig<U>) -> Self {
+        self.configs.push(config);
+        self
+    }
+}
+
+/// Polls a log's Signed Tree Head until it can be read, honoring `Retry-After` backoff.
+async fn fetch_log_size(client: &Client, url: &str, timeout: Duration) -> Result<usize, StreamError> {
+    loop {
+
+        let response = endpoint::get_log_size(client.clone(), url).await?;
 
         match response {
 
             Response::Data(size) => {
-                break size
+                return Ok(size)
             },
 
             Response::Limited(Some(duration)) => {
-                tokio::time::sleep({
-                    duration
-                }).await;
+                tokio::time::sleep(duration).await;
             },
 
             Response::Limited(None) => {
-                tokio::time::sleep({
-                    timeout
-                }).await;
+                tokio::time::sleep(timeout).await;
             },
 
             Response::Unhandled(400) => {
-                tokio::time::sleep({
-                    timeout
-                }).await;
+                tokio::time::sleep(timeout).await;
             },
 
             _ => continue,
         }
-    };
+    }
+}
 
-    let position = index.unwrap_or(size).min(size);
+/// Drives a single log's setup and `get-entries` pipeline to completion, forwarding each
+/// batch (tagged with `tag`) to `sender`. Used by [`stream_all`] to run every log on its own
+/// spawned task, so one log's backoff or hard error can never stall or abort another's.
+async fn stream_log(client: Client, url: String, workers: usize, batch: usize, timeout: Duration, verify: bool, index: Option<usize>, loaded: Option<usize>, tag: String, sender: mpsc::UnboundedSender<LogBatch>) -> Result<(), StreamError> {
+    let size = self::fetch_log_size(&client, url.as_str(), timeout).await?;
+    let position = index.unwrap_or_else(|| loaded.unwrap_or(size)).min(size);
 
-    let mut iterator = futures::stream::iter((position..)
-        .step_by(batch)).map(|start| {
+    let mut batches = self::log_entry_stream(client, url, workers, batch, timeout, verify, position);
+
+    while let Some(result) = batches.next().await {
+        let failed = result.is_err();
+        if sender.send((tag.clone(), result)).is_err() || failed { break }
+    }
+
+    Ok(())
+}
+
+/// Builds the `get-entries` pipeline shared by [`stream`] and [`stream_all`]: batches of
+/// `batch` entries starting at `position` are fetched `workers` at a time, each batch
+/// honoring its own `Retry-After` backoff and, when `verify` is set, its own inclusion
+/// proof verification, before being handed back in order.
+fn log_entry_stream(client: Client, url: String, workers: usize, batch: usize, timeout: Duration, verify: bool, position: usize) -> impl Stream<Item = Result<(usize, Vec<Entry>), StreamError>> {
+    futures::stream::iter((position..)
+        .step_by(batch)).map(move |start| {
 
             let client = client.clone();
             let url = url.clone();
 
-            tokio::spawn(async move {
+            let handle = tokio::spawn(async move {
                 let mut collection = Vec::with_capacity(batch);
-                
+
                 loop {
 
                     let start = start + collection.len();
@@ -185,14 +275,12 @@ where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
                     match response {
 
                         Response::Data(entries) => {
-                            if entries.is_empty() { 
-                                tokio::time::sleep({
-                                    timeout
-                                }).await;
+                            if entries.is_empty() {
+                                tokio::time::sleep(timeout).await;
                             }
-        
+
                             else {
-        
+
                                 collection.extend(entries);
                                 if collection.len() < batch { continue }
                                     else { break }
@@ -200,54 +288,481 @@ where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
                         },
 
                         Response::Limited(Some(duration)) => {
-                            tokio::time::sleep({
-                                duration
-                            }).await;
+                            tokio::time::sleep(duration).await;
                         },
 
                         Response::Limited(None) => {
-                            tokio::time::sleep({
-                                timeout
-                            }).await;
+                            tokio::time::sleep(timeout).await;
                         },
 
                         Response::Unhandled(400) => {
-                            tokio::time::sleep({
-                                timeout
-                            }).await;
+                            tokio::time::sleep(timeout).await;
                         },
 
                         _ => continue,
                     }
                 }
 
-                Ok(collection)
-            })
-        }).buffered(workers);
+                let collection = if verify {
+                    self::verify_batch(&client, url.as_str(), start, collection, timeout).await?
+                } else {
+                    collection.into_iter().map(|(entry, _)| entry).collect()
+                };
+
+                Ok((start, collection))
+            });
+
+            async move {
+                handle.await.map_err(StreamError::Task)?
+            }
+        }).buffered(workers)
+}
+
+pub async fn stream<U, F>(config : StreamConfig<U>, mut handler: F) -> Result<(), StreamError>
+where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
+
+    let StreamConfig {
+        timeout,
+        workers,
+        index,
+        batch,
+        checkpoint,
+        verify,
+        client,
+        url,
+    } = config;
+
+    let verify = verify.unwrap_or(false);
+
+    let mut checkpoint = checkpoint;
+
+    let client = client.unwrap_or_else(Client::new);
+    let url = String::from({
+        url.as_ref()
+    });
+
+    let workers = workers.unwrap_or(num_cpus::get()).max(1);
+    let batch = batch.unwrap_or(1000).max(1);
+
+    let timeout = timeout.unwrap_or({
+        Duration::from_secs(1)
+    });
+
+    let size = self::fetch_log_size(&client, url.as_str(), timeout).await?;
+
+    let position = index.unwrap_or_else(|| {
+        checkpoint.as_ref().and_then(|checkpoint| checkpoint.load()).unwrap_or(size)
+    }).min(size);
+
+    let mut iterator = self::log_entry_stream(client, url, workers, batch, timeout, verify, position);
 
     while let Some(result) = iterator.next().await {
-        for entry in result.map_err(|error| StreamError::Task(error))?? {
+        let (start, entries) = result?;
+        let count = entries.len();
+
+        for (offset, entry) in entries.into_iter().enumerate() {
             if handler(entry) { continue } else {
+                if let Some(ref mut checkpoint) = checkpoint {
+                    checkpoint.save(start + offset + 1);
+                }
+
                 return Ok(())
             }
         }
+
+        if let Some(ref mut checkpoint) = checkpoint {
+            checkpoint.save(start + count);
+        }
     }
 
     Ok(())
 }
 
+async fn verify_batch(client: &Client, url: &str, start: usize, collection: Vec<(Entry, [u8; 32])>, timeout: Duration) -> Result<Vec<Entry>, StreamError> {
+    if collection.is_empty() { return Ok(Vec::new()) }
+
+    let last_index = start + collection.len() - 1;
+
+    let tree_head = loop {
+
+        match endpoint::get_signed_tree_head(client.clone(), url).await? {
+
+            Response::Data(tree_head) if tree_head.tree_size() > last_index => break tree_head,
+
+            Response::Data(_) => {
+                tokio::time::sleep(timeout).await;
+            },
+
+            Response::Limited(Some(duration)) => {
+                tokio::time::sleep(duration).await;
+            },
+
+            Response::Limited(None) => {
+                tokio::time::sleep(timeout).await;
+            },
+
+            Response::Unhandled(_) => {
+                tokio::time::sleep(timeout).await;
+            },
+        }
+    };
+
+    // One round trip per entry is impractical at realistic batch sizes, so the proofs for
+    // this batch are fetched concurrently (in original order) rather than one at a time.
+    let workers = num_cpus::get().max(1);
+    let tree_head = &tree_head;
+
+    let verified = futures::stream::iter(collection.into_iter().enumerate())
+        .map(|(index, (entry, hash))| {
+            let client = client.clone();
+
+            async move {
+                let leaf_index = start + index;
+
+                let proof = loop {
+
+                    match endpoint::get_inclusion_proof(client.clone(), url, &hash, tree_head.tree_size()).await? {
+
+                        Response::Data(proof) => break proof,
+
+                        Response::Limited(Some(duration)) => {
+                            tokio::time::sleep(duration).await;
+                        },
+
+                        Response::Limited(None) => {
+                            tokio::time::sleep(timeout).await;
+                        },
+
+                        Response::Unhandled(_) => {
+                            tokio::time::sleep(timeout).await;
+                        },
+                    }
+                };
+
+                let ok = proof.leaf_index == leaf_index
+                    && audit::verify_inclusion(hash, proof.leaf_index, tree_head.tree_size(), &proof.audit_path, tree_head.root_hash());
+
+                if !ok {
+                    return Err(StreamError::from(LogError::ProofFailure))
+                }
+
+                Ok(entry.with_audit(AuditProof::new(tree_head.tree_size(), tree_head.root_hash())))
+            }
+        })
+        .buffered(workers)
+        .collect::<Vec<Result<Entry, StreamError>>>()
+        .await;
+
+    verified.into_iter().collect()
+}
+
+/// Fetches a consistency proof between two previously audited tree sizes and verifies that
+/// `second` is an append-only continuation of `first`, per RFC 6962 section 2.1.2.
+pub async fn verify_consistency<U>(url: U, first: &AuditProof, second: &AuditProof) -> Result<bool, StreamError>
+where U: AsRef<str> + Clone + Debug {
+
+    let client = Client::new();
+    let url = String::from({
+        url.as_ref()
+    });
+
+    let timeout = Duration::from_secs(1);
+
+    let path = loop {
+
+        match endpoint::get_consistency_proof(client.clone(), url.as_str(), first.tree_size(), second.tree_size()).await? {
+
+            Response::Data(path) => break path,
+
+            Response::Limited(Some(duration)) => {
+                tokio::time::sleep(duration).await;
+            },
+
+            Response::Limited(None) => {
+                tokio::time::sleep(timeout).await;
+            },
+
+            Response::Unhandled(_) => {
+                tokio::time::sleep(timeout).await;
+            },
+        }
+    };
+
+    Ok(audit::verify_consistency(first.tree_size(), second.tree_size(), &path, first.root_hash(), second.root_hash()))
+}
+
+type LogBatch = (String, Result<(usize, Vec<Entry>), StreamError>);
+
+struct Log {
+    checkpoint: Option<Box<dyn Checkpoint>>,
+}
+
+/// Follows every log in `config` concurrently, merging their entries into a single stream.
+/// The handler receives `(log_url, Entry)`; each log's setup and `get-entries` pipeline runs
+/// on its own spawned task, so one log's `Retry-After` backoff, slow `get-sth`, or hard error
+/// (bad URL, 4xx/5xx) never stalls or aborts the others. Returning `false` aborts every log's
+/// task; batch fetches that a log's pipeline has already spawned are not cancelled.
+pub async fn stream_all<U, F>(config: MultiStreamConfig<U>, mut handler: F) -> Result<(), StreamError>
+where U: AsRef<str> + Clone + Debug, F: FnMut(&str, Entry) -> bool {
+
+    let MultiStreamConfig { configs } = config;
+
+    let mut logs: HashMap<String, Log> = HashMap::new();
+    let mut tasks = Vec::new();
+    let (sender, mut receiver) = mpsc::unbounded_channel::<LogBatch>();
+
+    for config in configs {
+
+        let StreamConfig {
+            timeout,
+            workers,
+            index,
+            batch,
+            checkpoint,
+            verify,
+            client,
+            url,
+        } = config;
+
+        let client = client.unwrap_or_else(Client::new);
+        let url = String::from(url.as_ref());
+
+        let verify = verify.unwrap_or(false);
+        let workers = workers.unwrap_or(num_cpus::get()).max(1);
+        let batch = batch.unwrap_or(1000).max(1);
+
+        let timeout = timeout.unwrap_or({
+            Duration::from_secs(1)
+        });
+
+        // Resolved eagerly: cheap and synchronous, so the checkpoint itself never has to
+        // cross into the spawned task below, only the position it already yielded does.
+        let loaded = checkpoint.as_ref().and_then(|checkpoint| checkpoint.load());
+
+        let tag = url.clone();
+        let task_url = url.clone();
+        let sender = sender.clone();
+
+        tasks.push(tokio::spawn(async move {
+            let result = self::stream_log(client, task_url, workers, batch, timeout, verify, index, loaded, tag.clone(), sender.clone()).await;
+
+            if let Err(error) = result {
+                let _ = sender.send((tag, Err(error)));
+            }
+        }));
+
+        logs.insert(url, Log { checkpoint });
+    }
+
+    drop(sender);
+
+    while let Some((url, result)) = receiver.recv().await {
+        // A log's own error is isolated to that log: it stops producing further batches,
+        // but every other log's stream keeps running.
+        let (start, entries) = match result {
+            Ok(batch) => batch,
+            Err(_) => continue,
+        };
+
+        let log = logs.get_mut(url.as_str())
+            .expect("a tagged batch always has a matching log entry");
+
+        let count = entries.len();
+
+        for (offset, entry) in entries.into_iter().enumerate() {
+            if handler(url.as_str(), entry) { continue } else {
+                if let Some(ref mut checkpoint) = log.checkpoint {
+                    checkpoint.save(start + offset + 1);
+                }
+
+                for task in tasks.iter() {
+                    task.abort();
+                }
+
+                return Ok(())
+            }
+        }
+
+        if let Some(ref mut checkpoint) = log.checkpoint {
+            checkpoint.save(start + count);
+        }
+    }
+
+    Ok(())
+}
+
+/// Starts a stream that can be hot-reconfigured while it runs: returns the stream's future
+/// alongside a [`StreamControl`] handle for adjusting `timeout`/`batch` and pausing/resuming
+/// consumption, without tearing down and re-establishing the stream (which would lose
+/// in-flight batches and restart from `get-sth`).
+///
+/// Because `batch` can change mid-stream, batches cannot be safely prefetched concurrently
+/// off a speculative size the way [`stream`] does: `config.workers` is ignored here, and
+/// entries are fetched one batch at a time.
+pub fn stream_with_control<U, F>(config: StreamConfig<U>, handler: F) -> (impl std::future::Future<Output = Result<(), StreamError>>, StreamControl)
+where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
+
+    let timeout = config.timeout.unwrap_or_else(|| Duration::from_secs(1));
+    let batch = config.batch.unwrap_or(1000).max(1);
+
+    let control = control::StreamControl::new(timeout, batch);
+
+    (self::stream_controlled(config, handler, control.clone()), control)
+}
+
+async fn stream_controlled<U, F>(config: StreamConfig<U>, mut handler: F, control: StreamControl) -> Result<(), StreamError>
+where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
+
+    let StreamConfig {
+        timeout: _,
+        workers: _,
+        index,
+        batch: _,
+        checkpoint,
+        verify,
+        client,
+        url,
+    } = config;
+
+    let verify = verify.unwrap_or(false);
+
+    let mut checkpoint = checkpoint;
+
+    let client = client.unwrap_or_else(Client::new);
+    let url = String::from({
+        url.as_ref()
+    });
+
+    // A batch's real length can only be known once it has actually been fetched, and it may
+    // differ from whatever `control.batch()` reads mid-fetch if a caller hot-updates it. So,
+    // unlike `stream()`, batches here cannot be safely prefetched concurrently off speculative
+    // start offsets: each fetch is driven to completion before the next one's start is derived
+    // from its actual `collection.len()`, trading `workers` concurrency for correctness.
+    let size = loop {
+
+        let response = endpoint::get_log_size(client.clone(), url.clone()).await?;
+
+        match response {
+
+            Response::Data(size) => {
+                break size
+            },
+
+            Response::Limited(Some(duration)) => {
+                tokio::time::sleep(duration).await;
+            },
+
+            Response::Limited(None) => {
+                tokio::time::sleep(control.timeout()).await;
+            },
+
+            Response::Unhandled(400) => {
+                tokio::time::sleep(control.timeout()).await;
+            },
+
+            _ => continue,
+        }
+    };
+
+    let mut start = index.unwrap_or_else(|| {
+        checkpoint.as_ref().and_then(|checkpoint| checkpoint.load()).unwrap_or(size)
+    }).min(size);
+
+    loop {
+        control.wait_if_paused().await;
+
+        let client = client.clone();
+        let url = url.clone();
+        let control = control.clone();
+
+        let result = tokio::spawn(async move {
+            let mut collection = Vec::with_capacity(control.batch());
+
+            loop {
+
+                let batch = control.batch();
+                if collection.len() >= batch { break }
+
+                let timeout = control.timeout();
+                let position = start + collection.len();
+                let count = batch - collection.len();
+
+                let response = match endpoint::get_log_entries(client.clone(), url.as_str(), position, count).await {
+                    Err(error) => return Err(error),
+                    Ok(response) => response,
+                };
+
+                match response {
+
+                    Response::Data(entries) => {
+                        if entries.is_empty() {
+                            tokio::time::sleep(timeout).await;
+                        } else {
+                            collection.extend(entries);
+                        }
+                    },
+
+                    Response::Limited(Some(duration)) => {
+                        tokio::time::sleep(duration).await;
+                    },
+
+                    Response::Limited(None) => {
+                        tokio::time::sleep(timeout).await;
+                    },
+
+                    Response::Unhandled(400) => {
+                        tokio::time::sleep(timeout).await;
+                    },
+
+                    _ => continue,
+                }
+            }
+
+            let collection = if verify {
+                self::verify_batch(&client, url.as_str(), start, collection, control.timeout()).await?
+            } else {
+                collection.into_iter().map(|(entry, _)| entry).collect()
+            };
+
+            Ok((start, collection))
+        }).await;
+
+        let (start_of_batch, entries): (usize, Vec<Entry>) = result.map_err(StreamError::Task)??;
+        let count = entries.len();
+
+        for (offset, entry) in entries.into_iter().enumerate() {
+            if handler(entry) { continue } else {
+                if let Some(ref mut checkpoint) = checkpoint {
+                    checkpoint.save(start_of_batch + offset + 1);
+                }
+
+                return Ok(())
+            }
+        }
+
+        if let Some(ref mut checkpoint) = checkpoint {
+            checkpoint.save(start_of_batch + count);
+        }
+
+        start = start_of_batch + count;
+    }
+}
+
 pub mod blocking {
     
     use super::{
 
-        StreamConfig, 
-        StreamError, 
+        StreamConfig,
+        MultiStreamConfig,
+        StreamError,
         Entry,
     };
 
     use super::{Runtime};
     use super::{Debug};
-    
+
+    use tokio::runtime::{Handle};
+
     pub fn stream<U, F>(config : StreamConfig<U>, handler: F) -> Result<(), StreamError>
     where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
 
@@ -257,4 +772,34 @@ pub mod blocking {
             super::stream(config, handler).await
         })
     }
+
+    /// Block on a shared, already-running runtime instead of spinning up a private one,
+    /// so callers that already own a Tokio runtime can control its worker thread count.
+    pub fn stream_with_handle<U, F>(config : StreamConfig<U>, handler: F, handle: impl Into<Handle>) -> Result<(), StreamError>
+    where U: AsRef<str> + Clone + Debug, F: FnMut(Entry) -> bool {
+
+        handle.into().block_on(async {
+            super::stream(config, handler).await
+        })
+    }
+
+    pub fn stream_all<U, F>(config : MultiStreamConfig<U>, handler: F) -> Result<(), StreamError>
+    where U: AsRef<str> + Clone + Debug, F: FnMut(&str, Entry) -> bool {
+
+        let runtime = Runtime::new()?;
+
+        runtime.block_on(async {
+            super::stream_all(config, handler).await
+        })
+    }
+
+    /// Like [`stream_all`](super::stream_all), but driven on a caller-supplied runtime handle
+    /// rather than a private one spun up just for this call.
+    pub fn stream_all_with_handle<U, F>(config : MultiStreamConfig<U>, handler: F, handle: impl Into<Handle>) -> Result<(), StreamError>
+    where U: AsRef<str> + Clone + Debug, F: FnMut(&str, Entry) -> bool {
+
+        handle.into().block_on(async {
+            super::stream_all(config, handler).await
+        })
+    }
 }
\ No newline at end of file